@@ -1,5 +1,8 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
 use std::fmt;
 use std::ops::{Add, Mul};
+use std::str::FromStr;
 
 use image::{GenericImage, ImageBuffer};
 
@@ -21,12 +24,39 @@ impl Complex {
 
     pub const MAX_ITER: u16 = 1000;
 
+    /// The `(initial z, c)` pair for the escape-time recurrence of `kind`.
+    fn escape_init(self, kind: FractalKind) -> (Complex, Complex) {
+        let c = match kind {
+            FractalKind::Julia { c } => c,
+            _ => self,
+        };
+
+        let num = match kind {
+            FractalKind::Julia { .. } => self,
+            _ => Complex::new(0.0, 0.0),
+        };
+
+        (num, c)
+    }
+
+    /// Advances one step of the escape-time recurrence for `kind`.
+    fn escape_step(num: Complex, c: Complex, kind: FractalKind) -> Complex {
+        match kind {
+            FractalKind::Mandelbrot | FractalKind::Julia { .. } => num.pow(2) + c,
+            FractalKind::Multibrot { power } => num.pow(power) + c,
+            FractalKind::BurningShip => {
+                let folded = Complex::new(num.re.abs(), num.im.abs());
+                folded.pow(2) + c
+            }
+        }
+    }
+
     // Returns `None` if stable, otherwise `Some(iter)` if it diverges after `iter` iterations.
-    fn stability(self) -> Option<u16> {
-        let mut num = Complex::new(0.0, 0.0);
+    fn stability(self, kind: FractalKind) -> Option<u16> {
+        let (mut num, c) = self.escape_init(kind);
 
         for i in 0..Self::MAX_ITER {
-            num = num.pow(2) + self;
+            num = Self::escape_step(num, c, kind);
 
             if num.re * num.re + num.im * num.im > 2.0 * 2.0 {
                 return Some(i);
@@ -35,6 +65,52 @@ impl Complex {
 
         None
     }
+
+    // Continuous escape-time value, same contract as `stability` but without the banding.
+    fn smooth_stability(self, kind: FractalKind) -> Option<f64> {
+        const BAILOUT: f64 = 256.0;
+
+        let (mut num, c) = self.escape_init(kind);
+
+        for i in 0..Self::MAX_ITER {
+            num = Self::escape_step(num, c, kind);
+
+            let norm_sqr = num.re * num.re + num.im * num.im;
+
+            if norm_sqr > BAILOUT {
+                let mu = i as f64 + 1.0 - norm_sqr.ln().ln() / 2f64.ln();
+                return Some(mu.max(0.0));
+            }
+        }
+
+        None
+    }
+}
+
+/// Selects which escape-time recurrence `Complex::stability` evaluates.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum FractalKind {
+    Mandelbrot,
+    Multibrot { power: u32 },
+    Julia { c: Complex },
+    BurningShip,
+}
+
+/// Parses a `name` or `name:arg` spec into a [`FractalKind`].
+fn parse_fractal_kind(s: &str) -> Option<FractalKind> {
+    let (name, arg) = s.split_once(':').unwrap_or((s, ""));
+
+    match name {
+        "mandelbrot" => Some(FractalKind::Mandelbrot),
+        "burningship" => Some(FractalKind::BurningShip),
+        "multibrot" => Some(FractalKind::Multibrot {
+            power: arg.parse().ok()?,
+        }),
+        "julia" => Some(FractalKind::Julia {
+            c: parse_complex(arg)?,
+        }),
+        _ => None,
+    }
 }
 
 impl fmt::Display for Complex {
@@ -69,6 +145,174 @@ impl Mul<Self> for Complex {
     }
 }
 
+impl std::ops::Div<Self> for Complex {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.conj() * Complex::new(1.0 / rhs.norm_sqr(), 0.0)
+    }
+}
+
+impl Complex {
+    /// The complex conjugate, `re - im*i`.
+    fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// The squared modulus `re^2 + im^2`.
+    fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// The modulus (absolute value) `|z|`.
+    fn modulus(self) -> f64 {
+        self.norm_sqr().sqrt()
+    }
+
+    /// The argument (angle) of `z` in `-pi..=pi`, as per `atan2`.
+    fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+}
+
+/// A built-in complex function to visualize with domain coloring.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum DomainFunction {
+    /// `z^2 + 1`
+    SquarePlusOne,
+    /// `1/z`
+    Inverse,
+    /// `z^3 - 1`
+    CubeMinusOne,
+}
+
+impl DomainFunction {
+    fn evaluate(self, z: Complex) -> Complex {
+        match self {
+            DomainFunction::SquarePlusOne => z.pow(2) + Complex::new(1.0, 0.0),
+            DomainFunction::Inverse => Complex::new(1.0, 0.0) / z,
+            DomainFunction::CubeMinusOne => z.pow(3) + Complex::new(-1.0, 0.0),
+        }
+    }
+}
+
+/// Parses one of the built-in domain coloring function names.
+fn parse_domain_function(s: &str) -> Option<DomainFunction> {
+    match s {
+        "z2+1" => Some(DomainFunction::SquarePlusOne),
+        "1/z" => Some(DomainFunction::Inverse),
+        "z3-1" => Some(DomainFunction::CubeMinusOne),
+        _ => None,
+    }
+}
+
+/// Colors `z` by domain coloring `f(z)`: hue from `arg(f(z))`, brightness from `|f(z)|`.
+fn domain_color(z: Complex, f: DomainFunction) -> image::Rgb<u8> {
+    let value = f.evaluate(z);
+
+    let hue = value.arg().rem_euclid(2.0 * std::f64::consts::PI);
+    let image::Rgb([r, g, b]) = hue_to_rgb(hue);
+
+    let brightness = value.modulus().max(f64::MIN_POSITIVE).log2().rem_euclid(1.0);
+    let scale = |channel: u8| (channel as f64 * brightness) as u8;
+
+    image::Rgb([scale(r), scale(g), scale(b)])
+}
+
+/// Splits `s` on `sep` and parses both halves as `T`.
+fn parse_pair<T: FromStr>(s: &str, sep: char) -> Option<(T, T)> {
+    let (first, second) = s.split_once(sep)?;
+
+    Some((first.parse().ok()?, second.parse().ok()?))
+}
+
+/// Parses a `re,im` string into a [`Complex`] number.
+fn parse_complex(s: &str) -> Option<Complex> {
+    let (re, im) = parse_pair(s, ',')?;
+
+    Some(Complex::new(re, im))
+}
+
+/// Which per-pixel coloring scheme to render.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum RenderMode {
+    Fractal(FractalKind),
+    Domain(DomainFunction),
+}
+
+/// Parses a `FRACTAL_KIND` spec or a `domain:FUNCTION` spec into a [`RenderMode`].
+fn parse_render_mode(s: &str) -> Option<RenderMode> {
+    match s.strip_prefix("domain:") {
+        Some(func) => parse_domain_function(func).map(RenderMode::Domain),
+        None => parse_fractal_kind(s).map(RenderMode::Fractal),
+    }
+}
+
+struct Config {
+    img_width: u32,
+    img_height: u32,
+    top_left: Complex,
+    bottom_right: Complex,
+    output: String,
+    mode: RenderMode,
+    smooth: bool,
+    palette: Palette,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: schifo-mandelbrot <WIDTHxHEIGHT> <TOP_LEFT re,im> <BOTTOM_RIGHT re,im> <OUTPUT.png> [MODE] [PALETTE] [--smooth]\n\
+         MODE: mandelbrot (default) | burningship | multibrot:<power> | julia:<re,im> | domain:<z2+1|1/z|z3-1>\n\
+         PALETTE: rainbow (default) | grayscale | fire | dark (fractal MODEs only; domain:* always uses its own coloring)"
+    );
+    std::process::exit(1)
+}
+
+fn parse_args() -> Config {
+    let mut args: Vec<String> = std::env::args().collect();
+
+    let smooth = match args.iter().position(|a| a == "--smooth") {
+        Some(pos) => {
+            args.remove(pos);
+            true
+        }
+        None => false,
+    };
+
+    if args.len() < 5 || args.len() > 7 {
+        usage();
+    }
+
+    let (img_width, img_height) = parse_pair(&args[1], 'x').unwrap_or_else(|| usage());
+
+    if img_width == 0 || img_height == 0 {
+        usage();
+    }
+
+    let top_left = parse_complex(&args[2]).unwrap_or_else(|| usage());
+    let bottom_right = parse_complex(&args[3]).unwrap_or_else(|| usage());
+    let mode = match args.get(5) {
+        Some(spec) => parse_render_mode(spec).unwrap_or_else(|| usage()),
+        None => RenderMode::Fractal(FractalKind::Mandelbrot),
+    };
+    let palette = match args.get(6) {
+        Some(_) if matches!(mode, RenderMode::Domain(_)) => usage(),
+        Some(spec) => parse_palette(spec).unwrap_or_else(|| usage()),
+        None => Palette::Rainbow,
+    };
+
+    Config {
+        img_width,
+        img_height,
+        top_left,
+        bottom_right,
+        output: args[4].clone(),
+        mode,
+        smooth,
+        palette,
+    }
+}
+
 fn hue_to_rgb(rad: f64) -> image::Rgb<u8> {
     let third = std::f64::consts::FRAC_PI_3;
 
@@ -90,18 +334,288 @@ fn hue_to_rgb(rad: f64) -> image::Rgb<u8> {
     image::Rgb(color)
 }
 
-fn main() {
-    let img_height = 1000;
-    let img_width = 1000;
+// Linearly interpolates `fraction` (clamped to `0.0..=1.0`) across evenly-spaced `stops`.
+fn gradient(fraction: f64, stops: &[image::Rgb<u8>]) -> image::Rgb<u8> {
+    let segments = stops.len() - 1;
+    let scaled = fraction.clamp(0.0, 1.0) * segments as f64;
+    let index = (scaled as usize).min(segments - 1);
+    let t = scaled - index as f64;
 
-    let viewport_height = 4.0;
-    let viewport_width = 4.0;
+    let image::Rgb(a) = stops[index];
+    let image::Rgb(b) = stops[index + 1];
 
-    let zoom = 200.0;
+    let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
 
-    let portion_size = 1000;
+    image::Rgb([lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2])])
+}
+
+/// A named color scheme mapping escape-time fraction to color.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Palette {
+    /// The original full-saturation HSV wheel.
+    Rainbow,
+    /// Black to white.
+    Grayscale,
+    /// Black, through red and orange, to pale yellow.
+    Fire,
+    /// A cool, low-key black-to-blue-to-purple ramp.
+    Dark,
+}
+
+impl Palette {
+    fn color(self, fraction: f64) -> image::Rgb<u8> {
+        match self {
+            Palette::Rainbow => hue_to_rgb(fraction.clamp(0.0, 1.0) * std::f64::consts::PI * 2.0),
+            Palette::Grayscale => gradient(fraction, &[image::Rgb([0, 0, 0]), image::Rgb([255, 255, 255])]),
+            Palette::Fire => gradient(
+                fraction,
+                &[
+                    image::Rgb([0, 0, 0]),
+                    image::Rgb([128, 0, 0]),
+                    image::Rgb([255, 128, 0]),
+                    image::Rgb([255, 255, 200]),
+                ],
+            ),
+            Palette::Dark => gradient(
+                fraction,
+                &[
+                    image::Rgb([0, 0, 16]),
+                    image::Rgb([0, 32, 96]),
+                    image::Rgb([80, 0, 128]),
+                ],
+            ),
+        }
+    }
+
+    fn interior_color(self) -> image::Rgb<u8> {
+        match self {
+            Palette::Rainbow | Palette::Grayscale | Palette::Fire => image::Rgb([0, 0, 0]),
+            Palette::Dark => image::Rgb([0, 0, 16]),
+        }
+    }
+}
+
+/// Parses a palette name (`rainbow`, `grayscale`, `fire`, `dark`).
+fn parse_palette(s: &str) -> Option<Palette> {
+    match s {
+        "rainbow" => Some(Palette::Rainbow),
+        "grayscale" => Some(Palette::Grayscale),
+        "fire" => Some(Palette::Fire),
+        "dark" => Some(Palette::Dark),
+        _ => None,
+    }
+}
+
+/// Maps a pixel's `(true_x, true_y)` coordinates onto the complex plane.
+fn pixel_to_complex(
+    true_x: u32,
+    true_y: u32,
+    img_width: u32,
+    img_height: u32,
+    top_left: Complex,
+    bottom_right: Complex,
+) -> Complex {
+    let re =
+        top_left.re + (true_x as f64 / img_width as f64) * (bottom_right.re - top_left.re);
+    let im =
+        top_left.im + (true_y as f64 / img_height as f64) * (bottom_right.im - top_left.im);
+
+    Complex::new(re, im)
+}
+
+fn fraction_of(num: Complex, kind: FractalKind, smooth: bool) -> Option<f64> {
+    if smooth {
+        num.smooth_stability(kind)
+            .map(|mu| mu / Complex::MAX_ITER as f64)
+    } else {
+        num.stability(kind)
+            .map(|iter| iter as f64 / Complex::MAX_ITER as f64)
+    }
+}
+
+fn color_of(num: Complex, mode: RenderMode, smooth: bool, palette: Palette) -> image::Rgb<u8> {
+    match mode {
+        RenderMode::Fractal(kind) => match fraction_of(num, kind, smooth) {
+            Some(fraction) => palette.color(fraction),
+            None => palette.interior_color(),
+        },
+        RenderMode::Domain(f) => domain_color(num, f),
+    }
+}
+
+/// Renders one tile of the image, using the SIMD fast path when available.
+#[allow(clippy::too_many_arguments)]
+fn render_part(
+    start_horizontal: u32,
+    start_vertical: u32,
+    width: u32,
+    height: u32,
+    img_width: u32,
+    img_height: u32,
+    top_left: Complex,
+    bottom_right: Complex,
+    mode: RenderMode,
+    smooth: bool,
+    palette: Palette,
+) -> ImageBuffer<image::Rgb<u8>, Vec<u8>> {
+    #[cfg(feature = "simd")]
+    if !smooth && matches!(mode, RenderMode::Fractal(FractalKind::Mandelbrot)) {
+        return simd::render_part_simd(
+            start_horizontal,
+            start_vertical,
+            width,
+            height,
+            img_width,
+            img_height,
+            top_left,
+            bottom_right,
+            palette,
+        );
+    }
+
+    ImageBuffer::from_fn(width, height, |x, y| {
+        let true_x = x + start_horizontal;
+        let true_y = y + start_vertical;
+        let num = pixel_to_complex(true_x, true_y, img_width, img_height, top_left, bottom_right);
+
+        color_of(num, mode, smooth, palette)
+    })
+}
 
-    let top_left = (-0.76, -0.05);
+/// SIMD-vectorized escape-time path, gated behind the `simd` compile feature.
+#[cfg(feature = "simd")]
+mod simd {
+    use std::simd::prelude::*;
+
+    use super::{color_of, pixel_to_complex, Complex, FractalKind, Palette, RenderMode};
+
+    /// Number of points advanced per SIMD step.
+    const LANES: usize = 4;
+
+    /// Runs `z = z^2 + c` across `LANES` points at once, returning each lane's escape iteration.
+    fn stability_lanes(cr: Simd<f64, LANES>, ci: Simd<f64, LANES>, max_iter: u16) -> [Option<u16>; LANES] {
+        let bailout: Simd<f64, LANES> = Simd::splat(4.0);
+
+        let mut zr: Simd<f64, LANES> = Simd::splat(0.0);
+        let mut zi: Simd<f64, LANES> = Simd::splat(0.0);
+
+        let mut escaped_at = [None; LANES];
+        let mut escaped: Mask<i64, LANES> = Mask::splat(false);
+
+        for i in 0..max_iter {
+            let zr2 = zr * zr;
+            let zi2 = zi * zi;
+
+            let next_zr = zr2 - zi2 + cr;
+            let next_zi = zr * zi * Simd::splat(2.0) + ci;
+
+            zr = next_zr;
+            zi = next_zi;
+
+            let newly_escaped = (zr * zr + zi * zi).simd_gt(bailout) & !escaped;
+
+            for (lane, escaped_at) in escaped_at.iter_mut().enumerate() {
+                if newly_escaped.test(lane) {
+                    *escaped_at = Some(i);
+                }
+            }
+
+            escaped |= newly_escaped;
+
+            if escaped.all() {
+                break;
+            }
+        }
+
+        escaped_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn render_part_simd(
+        start_horizontal: u32,
+        start_vertical: u32,
+        width: u32,
+        height: u32,
+        img_width: u32,
+        img_height: u32,
+        top_left: Complex,
+        bottom_right: Complex,
+        palette: Palette,
+    ) -> image::ImageBuffer<image::Rgb<u8>, Vec<u8>> {
+        let mut part = image::ImageBuffer::new(width, height);
+
+        for y in 0..height {
+            let mut x = 0;
+
+            while x + LANES as u32 <= width {
+                let mut cr = [0.0; LANES];
+                let mut ci = [0.0; LANES];
+
+                for lane in 0..LANES {
+                    let num = pixel_to_complex(
+                        start_horizontal + x + lane as u32,
+                        start_vertical + y,
+                        img_width,
+                        img_height,
+                        top_left,
+                        bottom_right,
+                    );
+                    cr[lane] = num.re;
+                    ci[lane] = num.im;
+                }
+
+                let escapes = stability_lanes(
+                    Simd::from_array(cr),
+                    Simd::from_array(ci),
+                    Complex::MAX_ITER,
+                );
+
+                for (lane, escape) in escapes.into_iter().enumerate() {
+                    let color = match escape {
+                        Some(iter) => palette.color(iter as f64 / Complex::MAX_ITER as f64),
+                        None => palette.interior_color(),
+                    };
+                    part.put_pixel(x + lane as u32, y, color);
+                }
+
+                x += LANES as u32;
+            }
+
+            while x < width {
+                let num = pixel_to_complex(
+                    start_horizontal + x,
+                    start_vertical + y,
+                    img_width,
+                    img_height,
+                    top_left,
+                    bottom_right,
+                );
+                part.put_pixel(
+                    x,
+                    y,
+                    color_of(num, RenderMode::Fractal(FractalKind::Mandelbrot), false, palette),
+                );
+                x += 1;
+            }
+        }
+
+        part
+    }
+}
+
+fn main() {
+    let Config {
+        img_width,
+        img_height,
+        top_left,
+        bottom_right,
+        output,
+        mode,
+        smooth,
+        palette,
+    } = parse_args();
+
+    let portion_size = 1000;
 
     let mut image = ImageBuffer::new(img_width, img_height);
 
@@ -125,30 +639,18 @@ fn main() {
 
             let tx = tx.clone();
             pool.spawn_fifo(move || {
-                let part = ImageBuffer::from_fn(
+                let part = render_part(
+                    start_horizontal,
+                    start_vertical,
                     end_horizontal - start_horizontal,
                     end_vertical - start_vertical,
-                    |x, y| {
-                        let true_x = x + start_horizontal;
-                        let true_y = y + start_vertical;
-                        let re =
-                            top_left.0 + (true_x as f64 / img_width as f64) * viewport_width / zoom;
-                        let im = top_left.1
-                            + (true_y as f64 / img_height as f64) * viewport_height / zoom;
-
-                        let num = Complex::new(re, im);
-                        let stability = num.stability();
-
-                        if let Some(iter) = stability {
-                            hue_to_rgb(
-                                (iter as f64 / Complex::MAX_ITER as f64)
-                                    * std::f64::consts::PI
-                                    * 2.0,
-                            )
-                        } else {
-                            image::Rgb([0, 0, 0])
-                        }
-                    },
+                    img_width,
+                    img_height,
+                    top_left,
+                    bottom_right,
+                    mode,
+                    smooth,
+                    palette,
                 );
 
                 println!("Done computing ({}, {})", start_horizontal, start_vertical);
@@ -176,8 +678,7 @@ fn main() {
 
     println!("Received all, saving...");
 
-    let file = std::fs::File::create(format!("mandelbrot-{}x{}.png", img_width, img_height))
-        .expect("create img file");
+    let file = std::fs::File::create(&output).expect("create img file");
 
     let encoder = image::png::PngEncoder::new_with_quality(
         file,
@@ -235,4 +736,201 @@ mod tests {
         assert_eq!(c1.pow(2), c1 * c1);
         assert_eq!(c1.pow(3), c1 * c1 * c1);
     }
+
+    #[test]
+    fn parse_pair_valid() {
+        assert_eq!(parse_pair::<u32>("1000x2000", 'x'), Some((1000, 2000)));
+        assert_eq!(parse_pair::<f64>("-0.5,1.5", ','), Some((-0.5, 1.5)));
+    }
+
+    #[test]
+    fn parse_pair_invalid() {
+        assert_eq!(parse_pair::<u32>("1000", 'x'), None);
+        assert_eq!(parse_pair::<u32>("abcxdef", 'x'), None);
+    }
+
+    #[test]
+    fn parse_complex_valid() {
+        assert_eq!(parse_complex("-0.76,-0.05"), Some(Complex::new(-0.76, -0.05)));
+    }
+
+    #[test]
+    fn parse_complex_invalid() {
+        assert_eq!(parse_complex("not-a-complex"), None);
+    }
+
+    #[test]
+    fn parse_fractal_kind_variants() {
+        assert_eq!(
+            parse_fractal_kind("mandelbrot"),
+            Some(FractalKind::Mandelbrot)
+        );
+        assert_eq!(
+            parse_fractal_kind("burningship"),
+            Some(FractalKind::BurningShip)
+        );
+        assert_eq!(
+            parse_fractal_kind("multibrot:3"),
+            Some(FractalKind::Multibrot { power: 3 })
+        );
+        assert_eq!(
+            parse_fractal_kind("julia:-0.4,0.6"),
+            Some(FractalKind::Julia {
+                c: Complex::new(-0.4, 0.6)
+            })
+        );
+        assert_eq!(parse_fractal_kind("nonsense"), None);
+    }
+
+    #[test]
+    fn stability_mandelbrot_origin_is_stable() {
+        assert_eq!(Complex::new(0.0, 0.0).stability(FractalKind::Mandelbrot), None);
+    }
+
+    #[test]
+    fn stability_mandelbrot_far_point_diverges() {
+        assert_eq!(Complex::new(2.0, 2.0).stability(FractalKind::Mandelbrot), Some(0));
+    }
+
+    #[test]
+    fn stability_multibrot_matches_mandelbrot_at_power_two() {
+        let c = Complex::new(-0.5, 0.3);
+        assert_eq!(
+            c.stability(FractalKind::Mandelbrot),
+            c.stability(FractalKind::Multibrot { power: 2 })
+        );
+    }
+
+    #[test]
+    fn stability_julia_far_point_diverges() {
+        let kind = FractalKind::Julia {
+            c: Complex::new(-0.4, 0.6),
+        };
+        assert_eq!(Complex::new(2.0, 2.0).stability(kind), Some(0));
+    }
+
+    #[test]
+    fn stability_burning_ship_far_point_diverges() {
+        assert_eq!(
+            Complex::new(2.0, 2.0).stability(FractalKind::BurningShip),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn smooth_stability_origin_is_stable() {
+        assert_eq!(
+            Complex::new(0.0, 0.0).smooth_stability(FractalKind::Mandelbrot),
+            None
+        );
+    }
+
+    #[test]
+    fn smooth_stability_is_non_negative_and_close_to_integer_count() {
+        let point = Complex::new(-0.75, 0.1);
+        let iter = point.stability(FractalKind::Mandelbrot).unwrap();
+        let mu = point.smooth_stability(FractalKind::Mandelbrot).unwrap();
+
+        assert!(mu >= 0.0);
+        assert!((mu - iter as f64).abs() < 5.0);
+    }
+
+    #[test]
+    fn pixel_to_complex_maps_corners() {
+        let top_left = Complex::new(-1.0, -1.0);
+        let bottom_right = Complex::new(1.0, 1.0);
+
+        assert_eq!(
+            pixel_to_complex(0, 0, 100, 100, top_left, bottom_right),
+            top_left
+        );
+        assert_eq!(
+            pixel_to_complex(100, 100, 100, 100, top_left, bottom_right),
+            bottom_right
+        );
+        assert_eq!(
+            pixel_to_complex(50, 50, 100, 100, top_left, bottom_right),
+            Complex::new(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn fraction_of_interior_point_is_none() {
+        assert_eq!(
+            fraction_of(Complex::new(0.0, 0.0), FractalKind::Mandelbrot, false),
+            None
+        );
+        assert_eq!(
+            fraction_of(Complex::new(0.0, 0.0), FractalKind::Mandelbrot, true),
+            None
+        );
+    }
+
+    #[test]
+    fn div_is_inverse_of_mul() {
+        let c1 = Complex::new(1.0, -2.0);
+        let c2 = Complex::new(2.0, 4.0);
+
+        let quotient = (c1 * c2) / c2;
+        assert!((quotient.re - c1.re).abs() < 1e-9);
+        assert!((quotient.im - c1.im).abs() < 1e-9);
+    }
+
+    #[test]
+    fn domain_function_evaluate() {
+        let z = Complex::new(2.0, 0.0);
+        assert_eq!(
+            DomainFunction::SquarePlusOne.evaluate(z),
+            Complex::new(5.0, 0.0)
+        );
+        assert_eq!(DomainFunction::Inverse.evaluate(z), Complex::new(0.5, 0.0));
+        assert_eq!(
+            DomainFunction::CubeMinusOne.evaluate(z),
+            Complex::new(7.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn parse_render_mode_variants() {
+        assert_eq!(
+            parse_render_mode("mandelbrot"),
+            Some(RenderMode::Fractal(FractalKind::Mandelbrot))
+        );
+        assert_eq!(
+            parse_render_mode("domain:z2+1"),
+            Some(RenderMode::Domain(DomainFunction::SquarePlusOne))
+        );
+        assert_eq!(parse_render_mode("domain:nonsense"), None);
+    }
+
+    #[test]
+    fn gradient_endpoints_match_stops() {
+        let stops = [image::Rgb([0, 0, 0]), image::Rgb([255, 255, 255])];
+
+        assert_eq!(gradient(0.0, &stops), stops[0]);
+        assert_eq!(gradient(1.0, &stops), stops[1]);
+        assert_eq!(gradient(0.5, &stops), image::Rgb([128, 128, 128]));
+    }
+
+    #[test]
+    fn gradient_clamps_out_of_range_fractions() {
+        let stops = [image::Rgb([0, 0, 0]), image::Rgb([255, 255, 255])];
+
+        assert_eq!(gradient(-1.0, &stops), stops[0]);
+        assert_eq!(gradient(2.0, &stops), stops[1]);
+    }
+
+    #[test]
+    fn palette_grayscale_endpoints() {
+        assert_eq!(Palette::Grayscale.color(0.0), image::Rgb([0, 0, 0]));
+        assert_eq!(Palette::Grayscale.color(1.0), image::Rgb([255, 255, 255]));
+    }
+
+    #[test]
+    fn parse_palette_variants() {
+        assert_eq!(parse_palette("rainbow"), Some(Palette::Rainbow));
+        assert_eq!(parse_palette("fire"), Some(Palette::Fire));
+        assert_eq!(parse_palette("dark"), Some(Palette::Dark));
+        assert_eq!(parse_palette("nonsense"), None);
+    }
 }